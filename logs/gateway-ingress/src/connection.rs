@@ -1,11 +1,13 @@
 use crate::config::Configuration;
 use crate::debounced_pool::{DebouncedPool, DebouncedPoolUpdate};
+use crate::sink::{spawn_sink_worker, SinkRegistration};
 use anyhow::Result;
+use bitflags::bitflags;
 use futures::{stream, Stream, StreamExt as _1};
-use log::info;
 use static_assertions::assert_impl_all;
 use std::sync::{Arc, Mutex};
 use tokio::stream::StreamExt as _2;
+use tokio::sync::broadcast;
 use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
 
 /// Raw update messages that can come from the rest of the service,
@@ -15,16 +17,142 @@ use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
 pub enum UpdateMessage {
     GuildOnline(u64),
     GuildOffline(u64),
-    QueueOnline,
-    QueueOffline,
-    GatewayOnline,
-    GatewayOffline,
+    ComponentOnline(Dependency),
+    ComponentOffline(Dependency),
     GatewayHeartbeat,
+    /// Suspends uptime reporting for planned maintenance: internal state
+    /// (pool membership, connection status) keeps being tracked, but no
+    /// `UptimeEvent`s are emitted until a matching `Resume`
+    Pause,
+    /// Resumes uptime reporting after a `Pause`, emitting a single fresh
+    /// `Online` snapshot of the currently active guilds so the uptime
+    /// service resynchronizes. Nothing is emitted if tracking was not paused
+    /// or if the aggregate connection is offline at the time of the resume
+    Resume,
 }
 
-/// Represents a bulk uptime event that is dispatched to the uptime service
+/// A named upstream dependency whose connectivity contributes to the
+/// aggregate `ConnectionStatus`. Add new dependencies here as they're wired
+/// up (e.g. a database or a secondary shard) without touching the
+/// `UpdateMessage`/`ConnectionStatus` plumbing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Dependency {
+    Gateway,
+    Queue,
+}
+
+impl Dependency {
+    /// The single bit this dependency occupies in a `Dependencies` set
+    fn flag(self) -> Dependencies {
+        match self {
+            Self::Gateway => Dependencies::GATEWAY,
+            Self::Queue => Dependencies::QUEUE,
+        }
+    }
+}
+
+bitflags! {
+    /// Compact set of `Dependency` flags. Used both to track which
+    /// dependencies are currently online and, separately, which
+    /// dependencies are required for `ConnectionStatus::online()` to be true
+    /// (so optional components can be tracked without blocking the
+    /// aggregate online state).
+    pub struct Dependencies: u8 {
+        const GATEWAY = 0b0000_0001;
+        const QUEUE   = 0b0000_0010;
+    }
+}
+
+/// A point-in-time snapshot of the tracker's connection state, published to
+/// `Tracker::watch_status()` subscribers whenever the aggregate online
+/// signal flips — carries which dependencies are up, the aggregate online
+/// bool, and the guilds considered active at that moment, so scheduled
+/// indexing jobs and health endpoints can react immediately instead of
+/// polling.
 #[derive(Clone, Debug, PartialEq)]
-enum UptimeEvent {
+pub struct ConnectionSnapshot {
+    pub online: bool,
+    pub dependencies_online: Dependencies,
+    pub active_guilds: Vec<u64>,
+}
+
+/// Number of not-yet-received snapshots a `watch_status()` subscriber can
+/// lag behind before it starts missing transitions (see `broadcast::channel`
+/// docs); generous given snapshots are only published on online/offline
+/// flips, not per-event.
+const STATUS_BROADCAST_CAPACITY: usize = 32;
+
+/// Publishes a snapshot of `connection_status` to every `watch_status()`
+/// subscriber. Errors (meaning there are currently no subscribers) are
+/// ignored.
+fn publish_snapshot(
+    status_tx: &broadcast::Sender<ConnectionSnapshot>,
+    connection_status: &ConnectionStatus,
+    active_guilds: Vec<u64>,
+) {
+    let _ = status_tx.send(ConnectionSnapshot {
+        online: connection_status.online(),
+        dependencies_online: connection_status.online,
+        active_guilds,
+    });
+}
+
+/// Spawns the internal heartbeat timer task: while the connection is
+/// online, periodically flushes any pending debounced pool update and emits
+/// a `Heartbeat` with the current active-guild set — exactly the logic that
+/// used to live in the `GatewayHeartbeat` arm of `pipe_updates`, now driven
+/// internally so a stuttering upstream signal doesn't create gaps. Sending
+/// to the returned `UnboundedSender<()>` resets the interval rather than
+/// stacking with it, so an externally supplied `GatewayHeartbeat` doesn't
+/// double-beat.
+fn spawn_heartbeat_timer(
+    config: &Configuration,
+    pool_copy: DebouncedPool<u64>,
+    connection_status_mutex: Arc<Mutex<ConnectionStatus>>,
+) -> (UnboundedSender<()>, UnboundedReceiver<UptimeEvent>) {
+    let heartbeat_interval = config.heartbeat_interval;
+    let (reset_tx, mut reset_rx) = mpsc::unbounded_channel::<()>();
+    let (event_tx, event_rx) = mpsc::unbounded_channel::<UptimeEvent>();
+
+    tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = tokio::time::delay_for(heartbeat_interval) => {
+                    let connection_status = connection_status_mutex
+                        .lock()
+                        .expect("connection status poisoned");
+                    if connection_status.online() && !connection_status.paused() {
+                        let mut events = match pool_copy.release() {
+                            Some(update) => UptimeEvent::from_pool_update(update),
+                            None => Vec::new(),
+                        };
+                        events.push(UptimeEvent::Heartbeat(pool_copy.items()));
+                        drop(connection_status);
+                        for event in events {
+                            if event_tx.send(event).is_err() {
+                                return;
+                            }
+                        }
+                    }
+                }
+                reset = reset_rx.recv() => {
+                    // An external heartbeat arrived; loop back around so the
+                    // `delay_for` above is recreated (i.e. the interval resets)
+                    // instead of also emitting a heartbeat here
+                    if reset.is_none() {
+                        return;
+                    }
+                }
+            }
+        }
+    });
+
+    (reset_tx, event_rx)
+}
+
+/// Represents a bulk uptime event that is dispatched to the uptime service
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+pub(crate) enum UptimeEvent {
     Online(Vec<u64>),
     Offline(Vec<u64>),
     Heartbeat(Vec<u64>),
@@ -55,6 +183,7 @@ impl UptimeEvent {
 pub struct Tracker {
     updates: UnboundedReceiver<UpdateMessage>,
     debounced_guild_updates: UnboundedReceiver<DebouncedPoolUpdate<u64>>,
+    heartbeat_events: UnboundedReceiver<UptimeEvent>,
     state: TrackerState,
 }
 
@@ -65,38 +194,105 @@ impl Tracker {
         let (update_sender, update_receiver) = mpsc::unbounded_channel::<UpdateMessage>();
         let (active_guilds, debounced_guild_updates) =
             DebouncedPool::new(config.guild_uptime_debounce_delay.clone());
+        let connection_status = ConnectionStatus::new(config.required_dependencies);
+        let connection_status = Arc::new(Mutex::new(connection_status));
+        let (status_tx, _) = broadcast::channel(STATUS_BROADCAST_CAPACITY);
+        let (heartbeat_reset_tx, heartbeat_events) = spawn_heartbeat_timer(
+            &config,
+            active_guilds.clone(),
+            Arc::clone(&connection_status),
+        );
         let new_tracker = Self {
             updates: update_receiver,
             debounced_guild_updates,
+            heartbeat_events,
             state: TrackerState {
                 config,
                 active_guilds,
-                connection_status: Arc::new(Mutex::new(ConnectionStatus::new())),
+                connection_status,
+                status_tx,
+                heartbeat_reset_tx,
             },
         };
         (new_tracker, update_sender)
     }
 
+    /// Returns a stream of connection-status snapshots, one per online/offline
+    /// transition of the aggregate connection state. Each call returns an
+    /// independent subscriber backed by a `broadcast` channel, so every
+    /// subscriber observes every transition (not just the latest) as long as
+    /// it stays within `STATUS_BROADCAST_CAPACITY` snapshots of the sender;
+    /// a subscriber that lags further behind skips the transitions it
+    /// missed and continues from the oldest one still buffered. The stream immediately yields the current snapshot before forwarding
+    /// future transitions, so indexing schedulers and health endpoints can
+    /// react without polling and without missing the state they started in.
+    pub fn watch_status(&self) -> impl Stream<Item = ConnectionSnapshot> {
+        // Subscribe before reading the current snapshot (both while holding
+        // the lock): a transition published concurrently by `pipe_updates`
+        // is then either already reflected in `current` (published before we
+        // locked) or delivered afterwards through `status_rx` (published
+        // after we subscribed) — never both, but crucially never neither
+        let (current, status_rx) = {
+            let connection_status = self
+                .state
+                .connection_status
+                .lock()
+                .expect("connection status poisoned");
+            let status_rx = self.state.status_tx.subscribe();
+            let current = ConnectionSnapshot {
+                online: connection_status.online(),
+                dependencies_online: connection_status.online,
+                active_guilds: self.state.active_guilds.items(),
+            };
+            (current, status_rx)
+        };
+        let future_snapshots = stream::unfold(status_rx, |mut status_rx| async move {
+            loop {
+                match status_rx.recv().await {
+                    Ok(snapshot) => return Some((snapshot, status_rx)),
+                    // A lagging subscriber just missed some transitions; keep
+                    // draining towards the current one rather than erroring
+                    Err(broadcast::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::RecvError::Closed) => return None,
+                }
+            }
+        });
+        stream::once(async { current }).chain(future_snapshots)
+    }
+
     /// Runs the tracker to completion, listening for updates in the channel
-    /// and returning early if an error occurs with connecting to the uptime service initially
-    pub async fn run(self) -> Result<()> {
-        // First, connect to the uptime tracking service
-        // TODO implement
-
-        // Pipe uptime events to uptime service
-        self.stream_events()
-            .for_each(|event| async move {
-                // Note: we measure the time received at the sink,
-                // but the timing doesn't really matter that much as long as it is measured
-                // before a potential retry loop
-                // (the propagation delay between the stream processors
-                // is generally <250ms even if debounced)
-                let timestamp = architus_id::time::millisecond_ts();
-
-                // TODO implement
-                info!("Uptime event at {}: {:?}", timestamp, event);
-            })
-            .await;
+    /// and fanning the resulting uptime events out to every registered sink.
+    ///
+    /// Each sink is driven by its own task with its own channel, so a slow
+    /// or failing sink can't stall the others; `stream_events()` remains the
+    /// single source of truth, with each sink sampling it on its own
+    /// registered interval.
+    pub async fn run(self, sinks: Vec<SinkRegistration>) -> Result<()> {
+        let config = Arc::clone(&self.state.config);
+        let mut senders = Vec::with_capacity(sinks.len());
+        let mut workers = Vec::with_capacity(sinks.len());
+        for registration in sinks {
+            let (sender, receiver) = mpsc::unbounded_channel();
+            senders.push(sender);
+            workers.push(spawn_sink_worker(registration, Arc::clone(&config), receiver));
+        }
+
+        let mut events = self.stream_events().boxed();
+        while let Some(event) = events.next().await {
+            // Note: we measure the time received here, but the timing doesn't
+            // really matter that much as long as it is measured before a
+            // potential retry loop (the propagation delay between the stream
+            // processors is generally <250ms even if debounced)
+            let timestamp = architus_id::time::millisecond_ts();
+            for sender in &senders {
+                let _ = sender.send((event.clone(), timestamp));
+            }
+        }
+
+        // Dropping the senders closes every worker's channel so each one
+        // flushes its remaining buffer and exits
+        drop(senders);
+        futures::future::join_all(workers).await;
 
         Ok(())
     }
@@ -109,8 +305,11 @@ impl Tracker {
             .state
             .pipe_debounced_guild_updates(self.debounced_guild_updates);
 
-        // Emit the result of merging both streams
-        uptime_events.merge(debounced_uptime_events)
+        // Emit the result of merging all three streams: external updates,
+        // debounced guild pool updates, and the internal heartbeat timer
+        uptime_events
+            .merge(debounced_uptime_events)
+            .merge(self.heartbeat_events)
     }
 }
 
@@ -120,6 +319,8 @@ struct TrackerState {
     config: Arc<Configuration>,
     active_guilds: DebouncedPool<u64>,
     connection_status: Arc<Mutex<ConnectionStatus>>,
+    status_tx: broadcast::Sender<ConnectionSnapshot>,
+    heartbeat_reset_tx: UnboundedSender<()>,
 }
 
 assert_impl_all!(TrackerState: Sync, Send);
@@ -133,6 +334,8 @@ impl TrackerState {
     ) -> impl Stream<Item = UptimeEvent> {
         let pool_copy = self.active_guilds.clone();
         let connection_status_mutex = Arc::clone(&self.connection_status);
+        let status_tx = self.status_tx.clone();
+        let heartbeat_reset_tx = self.heartbeat_reset_tx.clone();
         in_stream.flat_map(move |update| {
             match update {
                 // For guild online/offline,
@@ -146,29 +349,38 @@ impl TrackerState {
                     pool_copy.remove(guild_id);
                     stream::iter(Vec::with_capacity(0))
                 }
-                UpdateMessage::QueueOnline | UpdateMessage::GatewayOnline => {
+                UpdateMessage::ComponentOnline(dependency) => {
                     let mut connection_status = connection_status_mutex
                         .lock()
                         .expect("connection status poisoned");
                     // Only emit an uptime event if the entire service just became online
-                    let events = if connection_status.online_update(update) {
+                    let events = if connection_status.online_update(dependency) {
                         pool_copy.release();
                         let items = pool_copy.items::<Vec<_>>();
-                        let events = vec![UptimeEvent::Online(items)];
-                        events
+                        publish_snapshot(&status_tx, &connection_status, items.clone());
+                        if connection_status.paused() {
+                            Vec::with_capacity(0)
+                        } else {
+                            vec![UptimeEvent::Online(items)]
+                        }
                     } else {
                         Vec::with_capacity(0)
                     };
                     stream::iter(events)
                 }
-                UpdateMessage::QueueOffline | UpdateMessage::GatewayOffline => {
+                UpdateMessage::ComponentOffline(dependency) => {
                     let mut connection_status = connection_status_mutex
                         .lock()
                         .expect("connection status poisoned");
                     // Only emit an uptime event if the entire service just became offline
-                    let events = if connection_status.offline_update(update) {
+                    let events = if connection_status.offline_update(dependency) {
                         let items = pool_copy.items::<Vec<_>>();
-                        let events = vec![UptimeEvent::Offline(items)];
+                        publish_snapshot(&status_tx, &connection_status, items.clone());
+                        let events = if connection_status.paused() {
+                            Vec::with_capacity(0)
+                        } else {
+                            vec![UptimeEvent::Offline(items)]
+                        };
                         pool_copy.release();
                         events
                     } else {
@@ -177,18 +389,31 @@ impl TrackerState {
                     stream::iter(events)
                 }
                 UpdateMessage::GatewayHeartbeat => {
-                    let connection_status = connection_status_mutex
+                    // The internal heartbeat timer (spawned in `Tracker::new`)
+                    // owns emitting the actual `Heartbeat` event; an external
+                    // heartbeat just resets that timer so the two don't stack
+                    let _ = heartbeat_reset_tx.send(());
+                    stream::iter(Vec::with_capacity(0))
+                }
+                UpdateMessage::Pause => {
+                    let mut connection_status = connection_status_mutex
                         .lock()
                         .expect("connection status poisoned");
-                    let events = if connection_status.online() {
-                        let mut events = if let Some(update) = pool_copy.release() {
-                            UptimeEvent::from_pool_update(update)
-                        } else {
-                            Vec::new()
-                        };
-                        let items = pool_copy.items();
-                        events.push(UptimeEvent::Heartbeat(items));
-                        events
+                    connection_status.set_paused(true);
+                    stream::iter(Vec::with_capacity(0))
+                }
+                UpdateMessage::Resume => {
+                    let mut connection_status = connection_status_mutex
+                        .lock()
+                        .expect("connection status poisoned");
+                    let was_paused = connection_status.paused();
+                    connection_status.set_paused(false);
+                    // Only resync if we were actually paused (a stray Resume
+                    // with no matching Pause is a no-op) and the connection
+                    // is actually online (otherwise this would falsely
+                    // report every guild online while a dependency is down)
+                    let events = if was_paused && connection_status.online() {
+                        vec![UptimeEvent::Online(pool_copy.items::<Vec<_>>())]
                     } else {
                         Vec::with_capacity(0)
                     };
@@ -209,7 +434,7 @@ impl TrackerState {
             let connection_status = connection_status_mutex
                 .lock()
                 .expect("connection status poisoned");
-            let events = if connection_status.online() {
+            let events = if connection_status.online() && !connection_status.paused() {
                 UptimeEvent::from_pool_update(update)
             } else {
                 Vec::with_capacity(0)
@@ -219,42 +444,51 @@ impl TrackerState {
     }
 }
 
-/// Holds the connection state to the gateway and queue
+/// Holds the online/offline state of every tracked dependency, aggregating
+/// them into a single online/offline signal based on the configured
+/// `required` set (dependencies outside that set can flap freely without
+/// affecting `online()`).
 struct ConnectionStatus {
-    gateway_online: bool,
-    queue_online: bool,
+    online: Dependencies,
+    required: Dependencies,
+    /// Set while uptime reporting is suspended for planned maintenance.
+    /// Guarded by the same lock as `online`/`required` since whether to emit
+    /// an event is always decided by reading it together with the
+    /// dependency state.
+    paused: bool,
 }
 
 impl ConnectionStatus {
-    fn new() -> Self {
+    fn new(required: Dependencies) -> Self {
         Self {
-            gateway_online: true,
-            queue_online: true,
+            online: Dependencies::all(),
+            required,
+            paused: false,
         }
     }
 
     fn online(&self) -> bool {
-        self.gateway_online && self.queue_online
+        self.online.contains(self.required)
+    }
+
+    fn paused(&self) -> bool {
+        self.paused
+    }
+
+    fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
     }
 
-    fn online_update(&mut self, update: UpdateMessage) -> bool {
+    fn online_update(&mut self, dependency: Dependency) -> bool {
         let offline_before = !self.online();
-        match update {
-            UpdateMessage::QueueOnline => self.queue_online = true,
-            UpdateMessage::GatewayOnline => self.gateway_online = true,
-            _ => {}
-        }
+        self.online.insert(dependency.flag());
         let online_after = self.online();
         offline_before && online_after
     }
 
-    fn offline_update(&mut self, update: UpdateMessage) -> bool {
+    fn offline_update(&mut self, dependency: Dependency) -> bool {
         let online_before = self.online();
-        match update {
-            UpdateMessage::QueueOffline => self.queue_online = false,
-            UpdateMessage::GatewayOffline => self.gateway_online = false,
-            _ => {}
-        }
+        self.online.remove(dependency.flag());
         let offline_after = !self.online();
         online_before && offline_after
     }
@@ -263,7 +497,7 @@ impl ConnectionStatus {
 #[cfg(test)]
 mod tests {
     use crate::config::Configuration;
-    use crate::connection::{Tracker, UpdateMessage, UptimeEvent};
+    use crate::connection::{Dependency, Tracker, UpdateMessage, UptimeEvent};
     use anyhow::Result;
     use futures::StreamExt;
     use std::collections::HashSet;
@@ -290,6 +524,27 @@ mod tests {
         HashSet::<T>::from_iter(v.iter().cloned())
     }
 
+    /// Reads from `event_stream` until something other than a `Heartbeat`
+    /// matching `stale` arrives. The internal heartbeat timer emits a
+    /// `Heartbeat` on every tick regardless of whether anything changed, so
+    /// tests that don't sleep for an exact number of ticks need to skip past
+    /// however many stale (unchanged) ones happened to queue up first.
+    async fn next_excluding_heartbeat(
+        event_stream: &mut (impl futures::Stream<Item = UptimeEvent> + Unpin),
+        stale: &[u64],
+    ) -> Option<UptimeEvent> {
+        let stale = set(&stale.to_vec());
+        loop {
+            let event = event_stream.next().await?;
+            if let UptimeEvent::Heartbeat(items) = &event {
+                if set(items) == stale {
+                    continue;
+                }
+            }
+            return Some(event);
+        }
+    }
+
     #[tokio::test]
     async fn test_basic_debounced() -> Result<()> {
         let mut config = Configuration::default();
@@ -312,13 +567,18 @@ mod tests {
     #[tokio::test]
     async fn test_heartbeat_flush() -> Result<()> {
         let mut config = Configuration::default();
-        config.guild_uptime_debounce_delay = Duration::from_millis(25);
+        // Keep the pool's own debounce delay long enough that it never
+        // auto-flushes within this test; every assertion below depends
+        // entirely on the internal heartbeat timer's forced release
+        config.guild_uptime_debounce_delay = Duration::from_millis(500);
+        config.heartbeat_interval = Duration::from_millis(20);
         let (tracker, update_tx) = Tracker::new(Arc::new(config));
         let mut event_stream = tracker.stream_events();
 
         update_tx.send(UpdateMessage::GuildOnline(0))?;
         update_tx.send(UpdateMessage::GuildOnline(1))?;
-        tokio::time::delay_for(Duration::from_millis(50)).await;
+
+        // The first heartbeat tick force-releases the pending pool update
         assert_eq!(
             event_stream.next().await.map(TestWrapper),
             Some(TestWrapper(UptimeEvent::Online(vec![0, 1])))
@@ -326,9 +586,15 @@ mod tests {
 
         update_tx.send(UpdateMessage::GuildOnline(2))?;
         update_tx.send(UpdateMessage::GuildOffline(0))?;
-        update_tx.send(UpdateMessage::GatewayHeartbeat)?;
+
+        // Every tick after the one above repeats a Heartbeat([0, 1]) until
+        // this update is processed; however many of those queued up while
+        // the test wasn't reading, skip past them to reach the batch that
+        // actually reflects the change
         assert_eq!(
-            event_stream.next().await.map(TestWrapper),
+            next_excluding_heartbeat(&mut event_stream, &[0, 1])
+                .await
+                .map(TestWrapper),
             Some(TestWrapper(UptimeEvent::Online(vec![2])))
         );
         assert_eq!(
@@ -358,15 +624,48 @@ mod tests {
             Some(TestWrapper(UptimeEvent::Online(vec![0, 1])))
         );
 
-        update_tx.send(UpdateMessage::GatewayOffline)?;
+        update_tx.send(UpdateMessage::ComponentOffline(Dependency::Gateway))?;
         assert_eq!(
             event_stream.next().await.map(TestWrapper),
             Some(TestWrapper(UptimeEvent::Offline(vec![0, 1])))
         );
 
-        update_tx.send(UpdateMessage::QueueOffline)?;
-        update_tx.send(UpdateMessage::QueueOnline)?;
-        update_tx.send(UpdateMessage::GatewayOnline)?;
+        update_tx.send(UpdateMessage::ComponentOffline(Dependency::Queue))?;
+        update_tx.send(UpdateMessage::ComponentOnline(Dependency::Queue))?;
+        update_tx.send(UpdateMessage::ComponentOnline(Dependency::Gateway))?;
+        assert_eq!(
+            event_stream.next().await.map(TestWrapper),
+            Some(TestWrapper(UptimeEvent::Online(vec![0, 1])))
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_pause_resume() -> Result<()> {
+        let mut config = Configuration::default();
+        config.guild_uptime_debounce_delay = Duration::from_millis(25);
+        let (tracker, update_tx) = Tracker::new(Arc::new(config));
+        let mut event_stream = tracker.stream_events();
+
+        update_tx.send(UpdateMessage::GuildOnline(0))?;
+        tokio::time::delay_for(Duration::from_millis(50)).await;
+        assert_eq!(
+            event_stream.next().await.map(TestWrapper),
+            Some(TestWrapper(UptimeEvent::Online(vec![0])))
+        );
+
+        // While paused, a dependency flip still updates internal state but
+        // must not emit an uptime event
+        update_tx.send(UpdateMessage::Pause)?;
+        update_tx.send(UpdateMessage::ComponentOffline(Dependency::Gateway))?;
+        update_tx.send(UpdateMessage::ComponentOnline(Dependency::Gateway))?;
+
+        // Resuming emits exactly one fresh `Online` snapshot of the
+        // currently active guilds
+        update_tx.send(UpdateMessage::GuildOnline(1))?;
+        tokio::time::delay_for(Duration::from_millis(50)).await;
+        update_tx.send(UpdateMessage::Resume)?;
         assert_eq!(
             event_stream.next().await.map(TestWrapper),
             Some(TestWrapper(UptimeEvent::Online(vec![0, 1])))
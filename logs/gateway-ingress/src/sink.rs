@@ -0,0 +1,385 @@
+use crate::config::Configuration;
+use crate::connection::UptimeEvent;
+use anyhow::Result;
+use async_trait::async_trait;
+use log::{error, info, warn};
+use rand::Rng;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc::UnboundedReceiver;
+use tokio::task::JoinHandle;
+
+/// Destination that buffered uptime events are forwarded to.
+///
+/// Implementations own their underlying connection: `connect`/`probe` should
+/// fail (rather than retry internally) so `ReconnectingSink` can drive a
+/// single, consistent backoff policy across every sink.
+#[async_trait]
+pub trait UptimeSink: Send {
+    /// (Re-)establishes the underlying connection to the uptime service
+    async fn connect(&mut self) -> Result<()>;
+
+    /// Performs a lightweight liveness check against the current connection,
+    /// used to detect a silent disconnect between sends
+    async fn probe(&mut self) -> Result<()>;
+
+    /// Forwards a batch of uptime events, each tagged with the
+    /// `architus_id::time::millisecond_ts()` timestamp it was measured at
+    async fn send(&mut self, events: Vec<(UptimeEvent, u64)>) -> Result<()>;
+}
+
+/// Sends uptime events to the primary uptime tracking service over HTTP,
+/// re-using a single client across calls
+pub struct UptimeServiceSink {
+    endpoint: String,
+    client: reqwest::Client,
+}
+
+impl UptimeServiceSink {
+    pub fn new(config: &Configuration) -> Self {
+        Self {
+            endpoint: config.uptime_service_endpoint.clone(),
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl UptimeSink for UptimeServiceSink {
+    async fn connect(&mut self) -> Result<()> {
+        self.probe().await
+    }
+
+    async fn probe(&mut self) -> Result<()> {
+        self.client
+            .get(&format!("{}/health", self.endpoint))
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+
+    async fn send(&mut self, events: Vec<(UptimeEvent, u64)>) -> Result<()> {
+        self.client
+            .post(&format!("{}/events", self.endpoint))
+            .json(&events)
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+}
+
+/// Exponential backoff with jitter, used to space out sink reconnect attempts.
+///
+/// Each call to `next_delay` returns `min(cap, base * 2^attempt)` scaled by a
+/// random factor in `[0.5, 1.0)`, then advances the attempt counter; `reset`
+/// should be called once a connection attempt succeeds.
+struct Backoff {
+    base: Duration,
+    cap: Duration,
+    attempt: u32,
+}
+
+impl Backoff {
+    fn new(base: Duration, cap: Duration) -> Self {
+        Self {
+            base,
+            cap,
+            attempt: 0,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.attempt = 0;
+    }
+
+    fn next_delay(&mut self) -> Duration {
+        let unjittered = self
+            .base
+            .saturating_mul(2u32.saturating_pow(self.attempt))
+            .min(self.cap);
+        let jitter = rand::thread_rng().gen_range(0.5, 1.0);
+        self.attempt = self.attempt.saturating_add(1);
+        unjittered.mul_f64(jitter)
+    }
+}
+
+/// Wraps an `UptimeSink`, transparently handling reconnection (with
+/// exponential backoff) and buffering/replaying events while the sink is
+/// disconnected so that no online/offline/heartbeat transition is lost
+/// across a brief outage.
+pub struct ReconnectingSink<S> {
+    inner: S,
+    connected: bool,
+    buffer: VecDeque<(UptimeEvent, u64)>,
+    buffer_capacity: usize,
+    backoff: Backoff,
+}
+
+impl<S: UptimeSink> ReconnectingSink<S> {
+    pub fn new(inner: S, config: &Configuration) -> Self {
+        Self {
+            inner,
+            connected: false,
+            buffer: VecDeque::with_capacity(config.uptime_sink_buffer_capacity),
+            buffer_capacity: config.uptime_sink_buffer_capacity,
+            backoff: Backoff::new(
+                config.uptime_sink_reconnect_base_delay,
+                config.uptime_sink_reconnect_max_delay,
+            ),
+        }
+    }
+
+    /// Pushes an event into the replay buffer, dropping the oldest entry once
+    /// the buffer is at capacity so a long outage degrades gracefully instead
+    /// of growing unbounded
+    fn buffer_event(&mut self, event: UptimeEvent, timestamp: u64) {
+        if self.buffer.len() >= self.buffer_capacity {
+            self.buffer.pop_front();
+        }
+        self.buffer.push_back((event, timestamp));
+    }
+
+    /// Blocks until the underlying sink connects, retrying with exponential
+    /// backoff + jitter, then replays any buffered events in order
+    pub async fn reconnect(&mut self) {
+        loop {
+            if let Err(err) = self.inner.connect().await {
+                let delay = self.backoff.next_delay();
+                warn!(
+                    "failed to connect to uptime sink, retrying in {:?}: {}",
+                    delay, err
+                );
+                tokio::time::delay_for(delay).await;
+                continue;
+            }
+
+            info!("connected to uptime sink");
+            self.connected = true;
+            self.backoff.reset();
+            self.flush_buffer().await;
+            if self.connected {
+                return;
+            }
+
+            // flush_buffer's replay failed and marked us disconnected again;
+            // retry the whole connect+replay cycle rather than leaving the
+            // sink stuck disconnected with nothing left to restart it
+            let delay = self.backoff.next_delay();
+            warn!(
+                "failed to replay buffered uptime events after reconnecting, retrying in {:?}",
+                delay
+            );
+            tokio::time::delay_for(delay).await;
+        }
+    }
+
+    /// Replays buffered events in order; re-buffers them and marks the sink
+    /// disconnected again if the replay itself fails
+    async fn flush_buffer(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+        let pending = self.buffer.drain(..).collect::<Vec<_>>();
+        if let Err(err) = self.inner.send(pending.clone()).await {
+            error!("failed to replay buffered uptime events: {}", err);
+            self.connected = false;
+            for (event, timestamp) in pending {
+                self.buffer_event(event, timestamp);
+            }
+        }
+    }
+
+    /// Handles a single outgoing event: buffers it while disconnected, or
+    /// forwards it (falling back to buffering + reconnecting on failure)
+    pub async fn handle(&mut self, event: UptimeEvent, timestamp: u64) {
+        if !self.connected {
+            self.buffer_event(event, timestamp);
+            return;
+        }
+
+        if let Err(err) = self.inner.send(vec![(event.clone(), timestamp)]).await {
+            warn!("lost connection to uptime sink: {}", err);
+            self.connected = false;
+            self.buffer_event(event, timestamp);
+            self.reconnect().await;
+        }
+    }
+
+    /// Runs a periodic liveness probe against the current connection,
+    /// reconnecting (and buffering any events in the meantime) if it fails
+    pub async fn check_liveness(&mut self) {
+        if !self.connected {
+            return;
+        }
+        if let Err(err) = self.inner.probe().await {
+            warn!("uptime sink liveness probe failed: {}", err);
+            self.connected = false;
+            self.reconnect().await;
+        }
+    }
+}
+
+#[async_trait]
+impl UptimeSink for Box<dyn UptimeSink> {
+    async fn connect(&mut self) -> Result<()> {
+        (**self).connect().await
+    }
+
+    async fn probe(&mut self) -> Result<()> {
+        (**self).probe().await
+    }
+
+    async fn send(&mut self, events: Vec<(UptimeEvent, u64)>) -> Result<()> {
+        (**self).send(events).await
+    }
+}
+
+/// A sink registered with a `Tracker`, paired with the interval on which its
+/// buffered/merged events are flushed. Different sinks can sample
+/// `stream_events()` at different rates (e.g. heartbeats every few seconds
+/// to one, aggregated rollups less often to another).
+pub struct SinkRegistration {
+    sink: Box<dyn UptimeSink>,
+    interval: Duration,
+}
+
+impl SinkRegistration {
+    pub fn new(sink: impl UptimeSink + 'static, interval: Duration) -> Self {
+        Self {
+            sink: Box::new(sink),
+            interval,
+        }
+    }
+}
+
+/// Spawns the background task that owns a single registered sink: it drains
+/// `events` into a per-sink buffer and, once `registration.interval`
+/// elapses, flushes the accumulated batch through a dedicated
+/// `ReconnectingSink`. Giving each sink its own task and channel means a
+/// slow or failing sink creates backpressure only on itself, never on the
+/// other registered sinks.
+pub(crate) fn spawn_sink_worker(
+    registration: SinkRegistration,
+    config: Arc<Configuration>,
+    mut events: UnboundedReceiver<(UptimeEvent, u64)>,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut sink = ReconnectingSink::new(registration.sink, &config);
+        sink.reconnect().await;
+
+        let mut flush_interval = tokio::time::interval(registration.interval);
+        let mut liveness_probe = tokio::time::interval(config.uptime_sink_probe_interval);
+        let mut pending = Vec::new();
+
+        loop {
+            tokio::select! {
+                event = events.recv() => {
+                    match event {
+                        Some(event) => pending.push(event),
+                        None => break,
+                    }
+                }
+                _ = flush_interval.tick() => {
+                    for (event, timestamp) in pending.drain(..) {
+                        sink.handle(event, timestamp).await;
+                    }
+                }
+                _ = liveness_probe.tick() => {
+                    sink.check_liveness().await;
+                }
+            }
+        }
+
+        // The event channel closed (the tracker is shutting down); flush
+        // whatever accumulated since the last tick before exiting
+        for (event, timestamp) in pending.drain(..) {
+            sink.handle(event, timestamp).await;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// Sink that fails to connect a fixed number of times before succeeding
+    /// (sends always succeed), used to exercise the reconnect + replay
+    /// behavior
+    struct FlakySink {
+        fail_connects_remaining: usize,
+        sent: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl UptimeSink for FlakySink {
+        async fn connect(&mut self) -> Result<()> {
+            if self.fail_connects_remaining > 0 {
+                self.fail_connects_remaining -= 1;
+                anyhow::bail!("simulated connect failure");
+            }
+            Ok(())
+        }
+
+        async fn probe(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn send(&mut self, events: Vec<(UptimeEvent, u64)>) -> Result<()> {
+            self.sent.fetch_add(events.len(), Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    fn test_config() -> Configuration {
+        let mut config = Configuration::default();
+        config.uptime_sink_reconnect_base_delay = Duration::from_millis(1);
+        config.uptime_sink_reconnect_max_delay = Duration::from_millis(5);
+        config.uptime_sink_buffer_capacity = 2;
+        config
+    }
+
+    #[tokio::test]
+    async fn test_buffers_while_disconnected_then_replays() {
+        let sent = Arc::new(AtomicUsize::new(0));
+        let config = test_config();
+        let mut sink = ReconnectingSink::new(
+            FlakySink {
+                fail_connects_remaining: 2,
+                sent: Arc::clone(&sent),
+            },
+            &config,
+        );
+
+        sink.handle(UptimeEvent::Online(vec![1]), 0).await;
+        sink.handle(UptimeEvent::Offline(vec![1]), 1).await;
+        assert_eq!(sent.load(Ordering::SeqCst), 0);
+
+        sink.reconnect().await;
+        assert_eq!(sent.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_buffer_drops_oldest_once_at_capacity() {
+        let sent = Arc::new(AtomicUsize::new(0));
+        let config = test_config();
+        let mut sink = ReconnectingSink::new(
+            FlakySink {
+                fail_connects_remaining: 0,
+                sent: Arc::clone(&sent),
+            },
+            &config,
+        );
+
+        sink.handle(UptimeEvent::Online(vec![1]), 0).await;
+        sink.handle(UptimeEvent::Online(vec![2]), 1).await;
+        sink.handle(UptimeEvent::Online(vec![3]), 2).await;
+        assert_eq!(sink.buffer.len(), 2);
+        assert_eq!(sink.buffer[0].1, 1);
+        assert_eq!(sink.buffer[1].1, 2);
+    }
+}